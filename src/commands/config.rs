@@ -0,0 +1,42 @@
+use log::info;
+
+use crate::context::config::Source;
+use crate::context::Config;
+use crate::Result;
+
+/// Prints the effective configuration, along with the layer each value was
+/// resolved from - a built-in default, the `jrny.toml` file, or a `JRNY_`
+/// prefixed environment variable - making debugging multi-environment
+/// setups far easier.
+pub struct ShowConfig;
+
+impl ShowConfig {
+    pub fn execute(cfg: &Config) -> Result<()> {
+        info!(
+            "revisions.directory = {} [{}]",
+            cfg.revisions.directory.value.display(),
+            describe(cfg.revisions.directory.source),
+        );
+        info!(
+            "table.schema = {} [{}]",
+            cfg.table.schema.value,
+            describe(cfg.table.schema.source),
+        );
+        info!(
+            "table.name = {} [{}]",
+            cfg.table.name.value,
+            describe(cfg.table.name.source),
+        );
+
+        Ok(())
+    }
+}
+
+fn describe(source: Source) -> &'static str {
+    match source {
+        Source::Default => "default",
+        Source::File => "file",
+        Source::Env => "env",
+        Source::CommandArg => "command arg",
+    }
+}
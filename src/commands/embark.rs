@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+
+use log::info;
+
+use crate::context::{Config, Environment};
+use crate::db::executor::Executor;
+use crate::{revisions, Result};
+
+/// Applies every pending revision, in order, inside a single transaction,
+/// recording each as it lands in the tracking table.
+pub struct Embark;
+
+impl Embark {
+    pub fn execute(cfg: &Config, env: &Environment) -> Result<()> {
+        let discovered = revisions::discover(&cfg.revisions.directory.value)?;
+        let mut executor = Executor::connect(&env.database.url)?;
+
+        revisions::ensure_table(&mut executor, cfg)?;
+
+        let applied_ids: HashSet<i64> = revisions::applied(&mut executor, cfg)?
+            .into_iter()
+            .map(|r| r.id)
+            .collect();
+
+        let pending: Vec<_> = discovered
+            .into_iter()
+            .filter(|r| !applied_ids.contains(&r.id))
+            .collect();
+
+        if pending.is_empty() {
+            info!("No pending revisions found");
+
+            return Ok(());
+        }
+
+        let mut txn = executor.transaction()?;
+
+        for revision in &pending {
+            info!("Applying revision {} - {}", revision.id, revision.title);
+
+            txn.batch_execute(&revision.up_sql()?)?;
+            txn.execute(
+                &format!(
+                    "insert into {}.{} (id) values ($1)",
+                    cfg.table.schema.value, cfg.table.name.value,
+                ),
+                &[&revision.id],
+            )?;
+        }
+
+        txn.commit()?;
+
+        info!("Applied {} revision(s)", pending.len());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+mod begin;
+mod config;
+mod embark;
+mod review;
+mod rollback;
+
+pub use begin::Begin;
+pub use config::ShowConfig;
+pub use embark::Embark;
+pub use review::Review;
+pub use rollback::{Rollback, RollbackTarget};
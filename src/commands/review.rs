@@ -0,0 +1,45 @@
+use std::collections::HashSet;
+
+use log::{info, warn};
+
+use crate::context::{Config, Environment};
+use crate::db::executor::Executor;
+use crate::{revisions, Result};
+
+/// Lists every revision found on disk with its applied or pending status,
+/// warning about each applied revision that has no down-script. Changes to,
+/// or removal of, already-applied revision files are not detected.
+pub struct Review;
+
+impl Review {
+    pub fn execute(cfg: &Config, env: &Environment) -> Result<()> {
+        let discovered = revisions::discover(&cfg.revisions.directory.value)?;
+        let mut executor = Executor::connect(&env.database.url)?;
+
+        revisions::ensure_table(&mut executor, cfg)?;
+
+        let applied: HashSet<i64> = revisions::applied(&mut executor, cfg)?
+            .into_iter()
+            .map(|r| r.id)
+            .collect();
+
+        for revision in &discovered {
+            let status = if applied.contains(&revision.id) {
+                "applied"
+            } else {
+                "pending"
+            };
+
+            info!("{} - {} [{}]", revision.id, revision.title, status);
+
+            if applied.contains(&revision.id) && revision.down_path.is_none() {
+                warn!(
+                    "  revision {} has been applied but has no down-script and cannot be rolled back",
+                    revision.id,
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use log::info;
+
+use crate::context::{Config, Environment};
+use crate::db::executor::Executor;
+use crate::{revisions, Error, Result};
+
+/// How far a rollback should revert the applied revisions.
+#[derive(Debug, Clone, Copy)]
+pub enum RollbackTarget {
+    /// Revert the `n` most recently applied revisions.
+    Steps(u32),
+
+    /// Revert every applied revision newer than the given id.
+    To(i64),
+}
+
+impl Default for RollbackTarget {
+    fn default() -> Self {
+        RollbackTarget::Steps(1)
+    }
+}
+
+/// Reverts previously applied revisions, most recent first, by running
+/// their paired down-scripts inside a single transaction and removing the
+/// corresponding rows from the tracking table.
+pub struct Rollback;
+
+impl Rollback {
+    pub fn execute(cfg: &Config, env: &Environment, target: RollbackTarget) -> Result<()> {
+        let discovered = revisions::discover(&cfg.revisions.directory.value)?;
+        let mut executor = Executor::connect(&env.database.url)?;
+
+        revisions::ensure_table(&mut executor, cfg)?;
+
+        let mut applied = revisions::applied(&mut executor, cfg)?;
+
+        // The most recently applied revisions are taken to be the ones with
+        // the highest ids; a lower-id revision applied after a higher-id one
+        // is still reverted after it.
+        applied.sort_by(|a, b| b.id.cmp(&a.id));
+
+        let to_revert: Vec<_> = match target {
+            RollbackTarget::Steps(steps) => applied.into_iter().take(steps as usize).collect(),
+            RollbackTarget::To(target_id) => applied
+                .into_iter()
+                .take_while(|r| r.id > target_id)
+                .collect(),
+        };
+
+        if to_revert.is_empty() {
+            info!("No applied revisions to roll back");
+
+            return Ok(());
+        }
+
+        let mut txn = executor.transaction()?;
+
+        for applied_revision in &to_revert {
+            let revision = discovered
+                .iter()
+                .find(|r| r.id == applied_revision.id)
+                .ok_or_else(|| {
+                    Error::Revision(format!(
+                        "revision {} is recorded as applied but its file could not be found",
+                        applied_revision.id,
+                    ))
+                })?;
+
+            info!("Rolling back revision {} - {}", revision.id, revision.title);
+
+            txn.batch_execute(&revision.down_sql()?)?;
+            txn.execute(
+                &format!(
+                    "delete from {}.{} where id = $1",
+                    cfg.table.schema.value, cfg.table.name.value,
+                ),
+                &[&revision.id],
+            )?;
+        }
+
+        txn.commit()?;
+
+        info!("Rolled back {} revision(s)", to_revert.len());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::{Error, Result};
+
+/// Where a resolved configuration value ultimately came from, in ascending
+/// order of precedence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    Default,
+    File,
+    Env,
+    CommandArg,
+}
+
+/// A configuration value paired with the layer it was resolved from, so
+/// that tools like `jrny config` can explain where an effective setting
+/// came from.
+#[derive(Debug, Clone)]
+pub struct Layered<T> {
+    pub value: T,
+    pub source: Source,
+}
+
+impl<T> Layered<T> {
+    fn new(value: T, source: Source) -> Self {
+        Self { value, source }
+    }
+}
+
+/// Effective configuration, merged from built-in defaults, the `jrny.toml`
+/// file, and `JRNY_`-prefixed environment variable overrides, in ascending
+/// order of precedence.
+#[derive(Debug)]
+pub struct Config {
+    pub revisions: RevisionsSettings,
+    pub table: TableSettings,
+}
+
+#[derive(Debug)]
+pub struct RevisionsSettings {
+    pub directory: Layered<PathBuf>,
+}
+
+#[derive(Debug)]
+pub struct TableSettings {
+    pub schema: Layered<String>,
+    pub name: Layered<String>,
+}
+
+// The raw, untyped shape of the `jrny.toml` file itself, deserialized before
+// being merged with environment overrides and defaults below.
+#[derive(Debug, Default, Deserialize)]
+struct FileConfig {
+    #[serde(default)]
+    revisions: FileRevisionsSettings,
+
+    #[serde(default)]
+    table: FileTableSettings,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FileRevisionsSettings {
+    directory: Option<PathBuf>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FileTableSettings {
+    schema: Option<String>,
+    name: Option<String>,
+}
+
+fn default_directory() -> PathBuf {
+    PathBuf::from("revisions")
+}
+
+fn default_schema() -> String {
+    "public".to_string()
+}
+
+fn default_table_name() -> String {
+    "jrny_revisions".to_string()
+}
+
+impl Config {
+    /// Reads the configuration file at the given path and merges it with
+    /// `JRNY_`-prefixed environment variable overrides and built-in
+    /// defaults.
+    ///
+    /// Each env var name is derived by uppercasing the key's dotted path and
+    /// replacing dashes with underscores, eg. `revisions.directory` becomes
+    /// `JRNY_REVISIONS_DIRECTORY`. Env values take precedence over the file,
+    /// and the file takes precedence over the built-in default.
+    pub fn from_filepath(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path).map_err(|_| {
+            Error::ConfNotFound(format!("configuration file not found: {}", path.display()))
+        })?;
+
+        let file: FileConfig = toml::from_str(&contents)?;
+
+        // Relative paths in the file (eg. a `revisions` directory) are
+        // resolved relative to the config file itself, not the current
+        // working directory, so that commands behave the same regardless of
+        // where they're run from within the project.
+        let conf_dir = path.parent().unwrap_or_else(|| Path::new("."));
+
+        Ok(Self {
+            revisions: RevisionsSettings {
+                directory: relative_to(
+                    conf_dir,
+                    layer(
+                        file.revisions.directory,
+                        "JRNY_REVISIONS_DIRECTORY",
+                        default_directory(),
+                        PathBuf::from,
+                    ),
+                ),
+            },
+            table: TableSettings {
+                schema: layer(
+                    file.table.schema,
+                    "JRNY_TABLE_SCHEMA",
+                    default_schema(),
+                    str::to_string,
+                ),
+                name: layer(
+                    file.table.name,
+                    "JRNY_TABLE_NAME",
+                    default_table_name(),
+                    str::to_string,
+                ),
+            },
+        })
+    }
+}
+
+/// Resolves a single layered value: an env var override beats the file
+/// value, which in turn beats the built-in default.
+fn layer<T>(
+    from_file: Option<T>,
+    env_var: &str,
+    default: T,
+    parse: impl Fn(&str) -> T,
+) -> Layered<T> {
+    if let Ok(raw) = env::var(env_var) {
+        return Layered::new(parse(&raw), Source::Env);
+    }
+
+    match from_file {
+        Some(value) => Layered::new(value, Source::File),
+        None => Layered::new(default, Source::Default),
+    }
+}
+
+/// Joins a relative path onto `base`, leaving absolute paths untouched.
+fn relative_to(base: &Path, layered: Layered<PathBuf>) -> Layered<PathBuf> {
+    if layered.value.is_absolute() {
+        layered
+    } else {
+        Layered::new(base.join(&layered.value), layered.source)
+    }
+}
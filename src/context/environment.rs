@@ -0,0 +1,69 @@
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::{Error, Result};
+
+/// The resolved database connection details used to apply or roll back
+/// revisions against a particular environment (eg. development, CI).
+#[derive(Debug)]
+pub struct Environment {
+    pub database: DatabaseEnvironment,
+}
+
+#[derive(Debug)]
+pub struct DatabaseEnvironment {
+    pub url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct EnvironmentFile {
+    database: DatabaseFile,
+}
+
+#[derive(Debug, Deserialize)]
+struct DatabaseFile {
+    url: String,
+}
+
+impl Environment {
+    /// Reads and parses the environment file at the given path. The
+    /// `database.url` value may reference process environment variables via
+    /// `${VAR}` so that secrets need not be committed to the file itself.
+    pub fn from_filepath(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path).map_err(|_| Error::EnvNotFound)?;
+        let parsed: EnvironmentFile = toml::from_str(&contents)?;
+
+        Ok(Self::from_database_url(&interpolate(&parsed.database.url)))
+    }
+
+    /// Builds an environment directly from a connection string, bypassing
+    /// the environment file entirely.
+    pub fn from_database_url(url: &str) -> Self {
+        Self {
+            database: DatabaseEnvironment { url: url.to_string() },
+        }
+    }
+}
+
+/// Replaces every `${VAR}` occurrence in `value` with the value of the `VAR`
+/// process environment variable, or an empty string if it isn't set.
+fn interpolate(value: &str) -> String {
+    let mut result = String::new();
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '$' && chars.peek() == Some(&'{') {
+            chars.next();
+
+            let var: String = chars.by_ref().take_while(|&c| c != '}').collect();
+
+            result.push_str(&std::env::var(&var).unwrap_or_default());
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
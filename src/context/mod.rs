@@ -1,5 +1,5 @@
 mod config;
 mod environment;
 
-pub use config::{Config, RevisionsSettings, TableSettings};
+pub use config::{Config, Layered, RevisionsSettings, Source, TableSettings};
 pub use environment::{Environment, DatabaseEnvironment};
\ No newline at end of file
@@ -0,0 +1,35 @@
+use postgres::{Client, NoTls, Transaction};
+
+use crate::Result;
+
+/// Thin wrapper around a `postgres::Client`, giving commands a single place
+/// to open connections and run revisions within a transaction.
+pub(crate) struct Executor {
+    client: Client,
+}
+
+impl Executor {
+    pub fn connect(database_url: &str) -> Result<Self> {
+        Ok(Self {
+            client: Client::connect(database_url, NoTls)?,
+        })
+    }
+
+    pub fn transaction(&mut self) -> Result<Transaction> {
+        Ok(self.client.transaction()?)
+    }
+
+    pub fn batch_execute(&mut self, sql: &str) -> Result<()> {
+        self.client.batch_execute(sql)?;
+
+        Ok(())
+    }
+
+    pub fn query(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn postgres::types::ToSql + Sync)],
+    ) -> Result<Vec<postgres::Row>> {
+        Ok(self.client.query(sql, params)?)
+    }
+}
@@ -0,0 +1,47 @@
+use std::path::{Path, PathBuf};
+
+/// Walks upward from `start`, inclusive, looking for a file named `filename`.
+/// Returns the first match, or `None` if the filesystem root is reached
+/// without finding one.
+pub(crate) fn find_upward(start: &Path, filename: &str) -> Option<PathBuf> {
+    let mut dir = Some(start);
+
+    while let Some(current) = dir {
+        let candidate = current.join(filename);
+
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+
+        dir = current.parent();
+    }
+
+    None
+}
+
+/// Walks upward from `start`, inclusive, looking for `filename`, stopping at
+/// the filesystem root or as soon as a directory containing a `.git` entry
+/// (directory or file) has been searched (taken to mark a project boundary). Returns every directory searched alongside the
+/// result, so that callers can report exactly where they looked.
+pub(crate) fn find_upward_bounded(start: &Path, filename: &str) -> (Option<PathBuf>, Vec<PathBuf>) {
+    let mut searched = Vec::new();
+    let mut dir = Some(start);
+
+    while let Some(current) = dir {
+        searched.push(current.to_path_buf());
+
+        let candidate = current.join(filename);
+
+        if candidate.is_file() {
+            return (Some(candidate), searched);
+        }
+
+        if current.join(".git").exists() {
+            break;
+        }
+
+        dir = current.parent();
+    }
+
+    (None, searched)
+}
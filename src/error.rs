@@ -0,0 +1,59 @@
+use std::fmt;
+
+/// Errors that can occur during any `jrny` operation, from initializing a new
+/// project to applying or rolling back revisions.
+#[derive(Debug)]
+pub enum Error {
+    ConfNotFound(String),
+    EnvNotFound,
+    NoDatabaseUrl { checked: Vec<String> },
+    InvalidConf(String),
+    InvalidEnv(String),
+    Io(std::io::Error),
+    Db(postgres::Error),
+    Toml(toml::de::Error),
+    Revision(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::ConfNotFound(msg) => write!(f, "{}", msg),
+            Error::EnvNotFound => write!(
+                f,
+                "no database connection could be established - see `jrny --help`"
+            ),
+            Error::NoDatabaseUrl { checked } => write!(
+                f,
+                "no database connection string was found; checked, in order: {}",
+                checked.join(", "),
+            ),
+            Error::InvalidConf(msg) => write!(f, "invalid configuration: {}", msg),
+            Error::InvalidEnv(msg) => write!(f, "invalid environment: {}", msg),
+            Error::Io(err) => write!(f, "{}", err),
+            Error::Db(err) => write!(f, "{}", err),
+            Error::Toml(err) => write!(f, "{}", err),
+            Error::Revision(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<postgres::Error> for Error {
+    fn from(err: postgres::Error) -> Self {
+        Error::Db(err)
+    }
+}
+
+impl From<toml::de::Error> for Error {
+    fn from(err: toml::de::Error) -> Self {
+        Error::Toml(err)
+    }
+}
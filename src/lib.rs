@@ -2,6 +2,7 @@ pub mod commands;
 
 mod context;
 mod db;
+mod discovery;
 mod error;
 mod logger;
 mod revisions;
@@ -14,6 +15,8 @@ pub use logger::Logger;
 
 pub(crate) use db::executor::Executor;
 
+use std::path::Path;
+
 // Crate result type
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -25,3 +28,61 @@ pub const ENV: &str = "jrny-env.toml";
 
 /// The default name of the example environment file
 pub const ENV_EX: &str = "jrny-env.example.toml";
+
+/// Searches upward from `start_dir` for the default config file (`jrny.toml`),
+/// stopping at the filesystem root or a `.git` boundary. Used when no
+/// `--conf-file` flag is given, so that commands can be run from anywhere
+/// inside a project tree.
+pub fn discover_conf_file(start_dir: &Path) -> Result<std::path::PathBuf> {
+    let (found, searched) = discovery::find_upward_bounded(start_dir, CONF);
+
+    found.ok_or_else(|| {
+        Error::ConfNotFound(format!(
+            "no {} found; searched: {}",
+            CONF,
+            searched
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        ))
+    })
+}
+
+/// Loads a `.env` file into the process environment, if one can be found by
+/// searching upward from `start_dir`. Variables already set in the process
+/// environment take precedence and are left untouched.
+pub fn load_dotenv(start_dir: &Path) {
+    if let Some(path) = discovery::find_upward(start_dir, ".env") {
+        let _ = dotenvy::from_path(&path);
+    }
+}
+
+/// Sets up a new journey in the given directory.
+pub fn begin(dir_path: &Path) -> Result<()> {
+    commands::Begin::execute(&dir_path.to_path_buf())
+}
+
+/// Applies every pending revision.
+pub fn embark(cfg: &Config, env: &Environment) -> Result<()> {
+    commands::Embark::execute(cfg, env)
+}
+
+/// Lists all revisions, reporting on any errors observed.
+pub fn review(cfg: &Config, env: &Environment) -> Result<()> {
+    commands::Review::execute(cfg, env)
+}
+
+/// Prints the effective configuration and where each value came from.
+pub fn show_config(cfg: &Config) -> Result<()> {
+    commands::ShowConfig::execute(cfg)
+}
+
+/// Reverts previously applied revisions.
+pub fn rollback(
+    cfg: &Config,
+    env: &Environment,
+    target: commands::RollbackTarget,
+) -> Result<()> {
+    commands::Rollback::execute(cfg, env, target)
+}
@@ -1,8 +1,9 @@
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 
-use clap::Parser;
+use clap::{CommandFactory, Parser};
+use clap_complete::{generate, Shell};
 use log::{warn, Level, LevelFilter, Log, Metadata, Record};
 use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
 
@@ -17,11 +18,10 @@ use jrny::{Error as JrnyError, Result as JrnyResult, CONF, ENV};
 PostgreSQL schema revisions made simple - just add SQL!
 
 Journey aims to offer a clean, easy to use workflow for managing schema revisions \
-for any project where plain SQL is appropriate, while also guaranteeing that:
-
-  * Revision files have not been changed or removed after being applied
-
-  * Revisions cannot be applied in different orders across environments",
+for any project where plain SQL is appropriate. Pending revisions are applied in \
+ascending id order; a pending revision whose id is lower than that of an already-applied \
+revision is still applied, after it. `jrny review` flags any applied revision whose down-script has since \
+been removed.",
     after_help = "\
 For any given command, use the `-h` flag to view a concise description of the command
 or `--help` for a more verbose description, eg. `jrny --help` or `jrny plan -h`.",
@@ -45,6 +45,9 @@ enum SubCommand {
     Plan(Plan),
     Review(Review),
     Embark(Embark),
+    Rollback(Rollback),
+    Config(ConfigCmd),
+    Completions(Completions),
 }
 
 #[derive(Parser, Debug)]
@@ -101,10 +104,11 @@ struct Review {
 
 #[derive(Parser, Debug)]
 #[command(
-    about = "Reviews existing revisions for errors and applies pending revisions",
+    about = "Applies every pending revision",
     long_about = "\
-Reviews existing revisions for errors. Applies pending revisions only if \
-no errors with existing revisions were found.",
+Applies every pending revision, in order, inside a single transaction, recording each \
+as it lands in the tracking table. Run `jrny review` first to check for errors with \
+already-applied revisions.",
 
 )]
 struct Embark {
@@ -115,12 +119,78 @@ struct Embark {
     env: CliEnvironment,
 }
 
+#[derive(Parser, Debug)]
+#[command(
+    about = "Reverts previously applied revisions",
+    long_about = "\
+Reverts the most recently applied revisions by running their paired down-scripts, \
+most recent first, and removing the corresponding rows from the tracking table. \
+Defaults to reverting a single revision.",
+)]
+struct Rollback {
+    #[command(flatten)]
+    cfg: CliConfig,
+
+    #[command(flatten)]
+    env: CliEnvironment,
+
+    #[arg(
+        help = "Number of revisions to revert, most recently applied first",
+        long_help = "\
+Number of most-recently-applied revisions to revert, most recent first. \
+Cannot be combined with `--to`.",
+        long,
+        default_value_t = 1,
+        conflicts_with = "to",
+    )]
+    steps: u32,
+
+    #[arg(
+        help = "Revert every applied revision newer than the given revision id",
+        long_help = "\
+Revert every applied revision with an id greater than the given target, leaving \
+the target revision itself applied.",
+        long,
+    )]
+    to: Option<i64>,
+}
+
+#[derive(Parser, Debug)]
+#[command(
+    about = "Prints the effective configuration and where each value came from",
+    long_about = "\
+Prints every effective configuration value along with the layer it was resolved from - a \
+built-in default, the jrny.toml file, or a JRNY_-prefixed environment variable - which is \
+useful for debugging multi-environment setups.",
+)]
+struct ConfigCmd {
+    #[command(flatten)]
+    cfg: CliConfig,
+}
+
+#[derive(Parser, Debug)]
+#[command(
+    about = "Generates a shell completion script",
+    long_about = "\
+Generates a completion script for the given shell, written to stdout, eg. \
+`jrny completions zsh > _jrny`. The generated file then needs to be placed somewhere \
+the shell will find it, per that shell's own completion conventions.",
+)]
+struct Completions {
+    #[arg(help = "Shell to generate completions for")]
+    shell: Shell,
+}
+
 #[derive(Parser, Debug)]
 struct CliConfig {
     #[arg(
         help = "\
-Path to required .toml configuration file, defaulting to `jrny.toml` in the \
-current directory",
+Path to .toml configuration file, defaulting to the nearest `jrny.toml` found by \
+searching upward from the current directory",
+        long_help = "\
+Path to .toml configuration file. If omitted, `jrny.toml` is searched for starting in the \
+current directory and walking upward through its parents, stopping at the filesystem root \
+or a directory containing a `.git` entry, so that commands can be run from anywhere inside a project tree.",
         short,
         long,
     )]
@@ -131,7 +201,10 @@ impl TryFrom<CliConfig> for Config {
     type Error = JrnyError;
 
     fn try_from(cli_cfg: CliConfig) -> Result<Self, Self::Error> {
-        let confpath = cli_cfg.conf_file.unwrap_or_else(|| PathBuf::from(CONF));
+        let confpath = match cli_cfg.conf_file {
+            Some(path) => path,
+            None => jrny::discover_conf_file(&std::env::current_dir()?)?,
+        };
 
         Self::from_filepath(&confpath)
     }
@@ -150,37 +223,56 @@ same directory as the configuration file",
 
     #[arg(
         help = "\
-Database connection string if overriding value from (or not using) an environment file",
+Database connection string, taking precedence over every other source",
+        long_help = "\
+Database connection string, taking precedence over every other source. If omitted, the \
+connection string is resolved in order from: the JRNY_DATABASE_URL (or DATABASE_URL) \
+environment variable, a `.env` file found by searching upward from the environment file's \
+directory, and finally the `database.url` key of the .toml environment file.",
         short,
         long,
     )]
     db_url: Option<String>,
 }
 
+/// Process environment variables that may supply the connection string,
+/// checked in this order, before falling back to the env file.
+const DATABASE_URL_VARS: [&str; 2] = ["JRNY_DATABASE_URL", "DATABASE_URL"];
+
 // Can't implement from/into traits if `Config` is involved, since it's technically foreign
 impl CliEnvironment {
     fn jrny_environment(self, cfg: &Config) -> JrnyResult<Environment> {
         let envpath = self
             .env_file
-            .unwrap_or_else(|| cfg.revisions.directory.parent().unwrap().join(ENV));
-
-        // This validates the env file, even if someone overrides it with the
-        // database url flag. The file itself is optional as long as the
-        // database url is supplied.
-        let env_file = (match Environment::from_filepath(&envpath) {
-            Ok(env) => Ok(Some(env)),
-            Err(err) => match err {
-                JrnyError::EnvNotFound => Ok(None),
-                e => Err(e),
-            },
-        })?;
-
-        match self.db_url {
-            Some(url) => Ok(Environment::from_database_url(&url)),
-            None => match env_file {
-                Some(env) => Ok(env),
-                None => Err(JrnyError::EnvNotFound),
-            },
+            .unwrap_or_else(|| cfg.revisions.directory.value.parent().unwrap().join(ENV));
+
+        // A `.env` file is entirely optional, and values within it are only
+        // applied if not already present in the process environment, so
+        // this has no effect if the caller has already set the variable.
+        jrny::load_dotenv(envpath.parent().unwrap_or_else(|| Path::new(".")));
+
+        if let Some(url) = self.db_url {
+            return Ok(Environment::from_database_url(&url));
+        }
+
+        if let Some(url) = DATABASE_URL_VARS.iter().find_map(|var| std::env::var(var).ok()) {
+            return Ok(Environment::from_database_url(&url));
+        }
+
+        // This validates the env file, even if there is no database url to
+        // be found within it, so that its absence or malformed contents are
+        // distinguished from it simply not being needed.
+        match Environment::from_filepath(&envpath) {
+            Ok(env) => Ok(env),
+            Err(JrnyError::EnvNotFound) => Err(JrnyError::NoDatabaseUrl {
+                checked: vec![
+                    "--db-url flag".to_string(),
+                    format!("{} environment variable", DATABASE_URL_VARS.join("/")),
+                    ".env file".to_string(),
+                    envpath.display().to_string(),
+                ],
+            }),
+            Err(e) => Err(e),
         }
     }
 }
@@ -236,6 +328,9 @@ fn main() -> ExitCode {
         SubCommand::Plan(cmd) => plan(cmd),
         SubCommand::Review(cmd) => review(cmd),
         SubCommand::Embark(cmd) => embark(cmd),
+        SubCommand::Rollback(cmd) => rollback(cmd),
+        SubCommand::Config(cmd) => config(cmd),
+        SubCommand::Completions(cmd) => completions(cmd),
     };
 
     // Returning the result directly would debugs print the error and exit with an
@@ -280,3 +375,30 @@ fn embark(cmd: Embark) -> JrnyResult<()> {
 
     jrny::embark(&cfg, &env)
 }
+
+fn config(cmd: ConfigCmd) -> JrnyResult<()> {
+    let cfg: Config = cmd.cfg.try_into()?;
+
+    jrny::show_config(&cfg)
+}
+
+fn completions(cmd: Completions) -> JrnyResult<()> {
+    let mut command = Jrny::command();
+    let name = command.get_name().to_string();
+
+    generate(cmd.shell, &mut command, name, &mut std::io::stdout());
+
+    Ok(())
+}
+
+fn rollback(cmd: Rollback) -> JrnyResult<()> {
+    let cfg: Config = cmd.cfg.try_into()?;
+    let env = cmd.env.jrny_environment(&cfg)?;
+
+    let target = match cmd.to {
+        Some(id) => jrny::RollbackTarget::To(id),
+        None => jrny::RollbackTarget::Steps(cmd.steps),
+    };
+
+    jrny::rollback(&cfg, &env, target)
+}
@@ -19,18 +19,36 @@ pub struct MightStartBlockComment;
 pub struct InBlockComment;
 pub struct MightEndBlockComment;
 
+/// Reading the `tag` portion of a possible dollar-quote, eg. the `tag` in
+/// `$tag$ ... $tag$`. An empty tag is valid, so `$$ ... $$` delimits a string
+/// just as well as `$tag$ ... $tag$` does. A tag must start with a letter or
+/// underscore - critically, `$1`, `$2`, etc. are positional parameters, not
+/// dollar-quotes, so a leading digit aborts back to `Start`.
+pub struct MightStartDollarTag(String);
+
+/// Inside the body of a dollar-quoted string delimited by `tag`. Everything
+/// is carried verbatim - no other quoting or commenting rules apply - until
+/// the exact closing delimiter `$tag$` is found. A different tag (eg. an
+/// inner `$$` while inside `$body$ ... $body$`) does not close this string.
+pub struct InDollarString(String);
+
+/// Having seen the `$` that might begin the `$tag$` closing a dollar-quoted
+/// string, tracks how much of that closing delimiter has been matched.
+pub struct MightEndDollarString(String, usize);
+
 
 impl State for Start { // 1
     fn can_terminate(&self) -> bool {
         true
     }
-    
+
     fn accept(&self, s: &str) -> (Action, Box<dyn State>) {
         match s {
             "'"  => (Action::Append, Box::new(InString)),
             "\"" => (Action::Append, Box::new(InDelimitedIdentifier)),
             "-"  => (Action::Carry,  Box::new(MightStartInlineComment)),
             "/"  => (Action::Carry,  Box::new(MightStartBlockComment)),
+            "$"  => (Action::Carry,  Box::new(MightStartDollarTag(String::new()))),
             _    => (Action::Append, Box::new(Start)),
 
         }
@@ -68,6 +86,7 @@ impl State for MightStartInlineComment { // 4
             "\"" => (Action::Append, Box::new(InDelimitedIdentifier)),
             "--" => (Action::Ignore, Box::new(InInlineComment)),
             "/"  => (Action::Carry,  Box::new(MightStartBlockComment)),
+            "$"  => (Action::Carry,  Box::new(MightStartDollarTag(String::new()))),
             _    => (Action::Append, Box::new(Start)),
         }
     }
@@ -93,6 +112,7 @@ impl State for MightStartBlockComment { // 6
             "\"" => (Action::Append, Box::new(InDelimitedIdentifier)),
             "-"  => (Action::Ignore, Box::new(MightStartInlineComment)),
             "/*" => (Action::Carry,  Box::new(InBlockComment)),
+            "$"  => (Action::Carry,  Box::new(MightStartDollarTag(String::new()))),
             _    => (Action::Append, Box::new(Start)),
         }
     }
@@ -115,3 +135,162 @@ impl State for MightEndBlockComment { // 8
         }
     }
 }
+
+impl State for MightStartDollarTag { // 9
+    // `s` is whatever has been carried since the opening `$` plus this new
+    // grapheme - eg. `$b`, then `$bo`, and so on - so only the
+    // *last* character of `s` is new; the tag read so far lives in `self.0`,
+    // not in `s` itself.
+    fn accept(&self, s: &str) -> (Action, Box<dyn State>) {
+        let tag = &self.0;
+        let c = s.chars().last().expect("carried grapheme is never empty");
+
+        match c {
+            // The tag is complete (possibly empty, eg. `$$`) - everything
+            // carried so far, including this closing `$`, is the opening
+            // delimiter.
+            '$' => (Action::Append, Box::new(InDollarString(tag.clone()))),
+
+            // A digit immediately following the opening `$` (with no tag
+            // characters read yet) means this is a positional parameter like
+            // `$1`, not a dollar-quote, so bail back out to `Start`.
+            c if tag.is_empty() && c.is_ascii_digit() => (Action::Append, Box::new(Start)),
+
+            // Otherwise, a valid tag character (letters, digits, underscore)
+            // keeps reading the tag.
+            c if is_tag_char(c) => {
+                let mut tag = tag.clone();
+                tag.push(c);
+
+                (Action::Carry, Box::new(MightStartDollarTag(tag)))
+            }
+
+            // Anything else means this was never a dollar-quote at all.
+            _ => (Action::Append, Box::new(Start)),
+        }
+    }
+}
+
+impl State for InDollarString { // 10
+    fn accept(&self, s: &str) -> (Action, Box<dyn State>) {
+        match s {
+            "$" => (Action::Carry, Box::new(MightEndDollarString(self.0.clone(), 0))),
+            _   => (Action::Append, Box::new(InDollarString(self.0.clone()))),
+        }
+    }
+}
+
+impl State for MightEndDollarString { // 11
+    // As in `MightStartDollarTag`, `s` is everything carried since the `$`
+    // that might be closing the string, so only its last character - the
+    // newly arrived grapheme - is examined; how much of the delimiter has
+    // matched so far is tracked by `self.1`, not derived from `s`.
+    fn accept(&self, s: &str) -> (Action, Box<dyn State>) {
+        let (tag, matched) = (&self.0, self.1);
+        let c = s.chars().last().expect("carried grapheme is never empty");
+
+        // What's left to match of the closing delimiter, having already
+        // seen the `$` that put us in this state - ie. `tag` followed by
+        // the final `$`.
+        let remaining: Vec<char> = tag.chars().chain(std::iter::once('$')).collect();
+        let expected = remaining[matched];
+
+        if c == expected && matched + 1 == remaining.len() {
+            // The full `$tag$` has now been matched - this closes the string.
+            (Action::Append, Box::new(Start))
+        } else if c == expected {
+            (Action::Carry, Box::new(MightEndDollarString(tag.clone(), matched + 1)))
+        } else if c == '$' {
+            // A mismatch, but this character is itself a `$` - rather than
+            // being ordinary content, it's the start of a brand new attempt
+            // at the closing delimiter, so re-arm instead of falling back to
+            // `InDollarString` (which would only re-arm on a *later* `$`).
+            (Action::Carry, Box::new(MightEndDollarString(tag.clone(), 0)))
+        } else {
+            // A mismatch - whatever was carried while attempting to match
+            // the closing delimiter was not actually one, so it's ordinary
+            // string content.
+            (Action::Append, Box::new(InDollarString(tag.clone())))
+        }
+    }
+}
+
+fn is_tag_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Drives the state machine over `input` one grapheme at a time,
+    /// reconstructing the text that would remain once comments have been
+    /// stripped - the same round trip a real consumer of `State` would
+    /// perform. `Carry` accumulates onto a pending buffer; `Append` flushes
+    /// it (plus the current grapheme) to the output, and `Ignore` drops it,
+    /// in both cases resetting the buffer for the next grapheme.
+    fn run(input: &str) -> String {
+        let mut state: Box<dyn State> = Box::new(Start);
+        let mut pending = String::new();
+        let mut output = String::new();
+
+        for grapheme in input.chars().map(|c| c.to_string()) {
+            let s = format!("{pending}{grapheme}");
+            let (action, next) = state.accept(&s);
+
+            match action {
+                Action::Append => {
+                    output.push_str(&s);
+                    pending.clear();
+                }
+                Action::Ignore => pending.clear(),
+                Action::Carry => pending = s,
+            }
+
+            state = next;
+        }
+
+        output
+    }
+
+    #[test]
+    fn empty_tag_dollar_quote_is_not_a_comment() {
+        let sql = "select $$ -- not a comment $$;";
+
+        assert_eq!(run(sql), sql);
+    }
+
+    #[test]
+    fn tagged_dollar_quote_is_not_closed_by_a_different_tag() {
+        let sql = "select $body$ -- x $$ y $body$;";
+
+        assert_eq!(run(sql), sql);
+    }
+
+    #[test]
+    fn dollar_immediately_before_the_closing_delimiter_still_closes_it() {
+        // Body is `X$`, closed by `$t$`, followed by a literal `;` - the
+        // mismatching `$` right before the real close must re-arm a fresh
+        // closing attempt rather than being swallowed as body content.
+        let sql = "$t$X$$t$;";
+
+        assert_eq!(run(sql), sql);
+    }
+
+    #[test]
+    fn positional_parameter_is_not_a_dollar_quote() {
+        let sql = "select $1 where id = $2";
+
+        assert_eq!(run(sql), sql);
+    }
+
+    #[test]
+    fn block_comment_is_still_stripped() {
+        assert_eq!(run("select /* a comment */ 1"), "select  1");
+    }
+
+    #[test]
+    fn inline_comment_is_still_stripped() {
+        assert_eq!(run("select 1 -- trailing\nselect 2"), "select 1 \nselect 2");
+    }
+}
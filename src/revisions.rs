@@ -0,0 +1,119 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::context::config::Config;
+use crate::db::executor::Executor;
+use crate::{Error, Result};
+
+/// A single schema revision discovered on disk, optionally paired with a
+/// down-script (`<id>.<timestamp>.<title>.down.sql`) that can undo it.
+#[derive(Debug, Clone)]
+pub struct Revision {
+    pub id: i64,
+    pub timestamp: String,
+    pub title: String,
+    pub up_path: PathBuf,
+    pub down_path: Option<PathBuf>,
+}
+
+impl Revision {
+    pub fn up_sql(&self) -> Result<String> {
+        Ok(fs::read_to_string(&self.up_path)?)
+    }
+
+    pub fn down_sql(&self) -> Result<String> {
+        match &self.down_path {
+            Some(path) => Ok(fs::read_to_string(path)?),
+            None => Err(Error::Revision(format!(
+                "revision {} ({}) has no down-script and cannot be rolled back",
+                self.id, self.title,
+            ))),
+        }
+    }
+}
+
+/// A revision id as recorded in the tracking table.
+#[derive(Debug, Clone)]
+pub struct AppliedRevision {
+    pub id: i64,
+}
+
+/// Scans the revisions directory, pairing each `<id>.<timestamp>.<title>.sql`
+/// file with its `.down.sql` counterpart if one exists, and returns them in
+/// ascending id order.
+pub fn discover(dir: &Path) -> Result<Vec<Revision>> {
+    let mut revisions = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let filename = match path.file_name().and_then(|n| n.to_str()) {
+            Some(name) => name,
+            None => continue,
+        };
+
+        if !filename.ends_with(".sql") || filename.ends_with(".down.sql") {
+            continue;
+        }
+
+        let stem = filename.trim_end_matches(".sql");
+        let parts: Vec<&str> = stem.splitn(3, '.').collect();
+
+        let (id, timestamp, title) = match parts[..] {
+            [id, timestamp, title] => (id, timestamp, title),
+            _ => {
+                return Err(Error::Revision(format!(
+                    "unrecognized revision filename: {}",
+                    filename
+                )))
+            }
+        };
+
+        let id: i64 = id
+            .parse()
+            .map_err(|_| Error::Revision(format!("invalid revision id: {}", filename)))?;
+
+        let down_path = dir.join(format!("{}.down.sql", stem));
+        let down_path = down_path.exists().then_some(down_path);
+
+        revisions.push(Revision {
+            id,
+            timestamp: timestamp.to_string(),
+            title: title.to_string(),
+            up_path: path,
+            down_path,
+        });
+    }
+
+    revisions.sort_by_key(|r| r.id);
+
+    Ok(revisions)
+}
+
+/// Creates the tracking table and its schema if they don't already exist.
+pub fn ensure_table(executor: &mut Executor, cfg: &Config) -> Result<()> {
+    executor.batch_execute(&format!(
+        "create schema if not exists {schema};
+         create table if not exists {schema}.{table} (
+             id bigint primary key,
+             applied_at timestamptz not null default now()
+         )",
+        schema = cfg.table.schema.value,
+        table = cfg.table.name.value,
+    ))
+}
+
+/// Returns every revision recorded as applied, in ascending id order.
+pub fn applied(executor: &mut Executor, cfg: &Config) -> Result<Vec<AppliedRevision>> {
+    let rows = executor.query(
+        &format!(
+            "select id from {}.{} order by id",
+            cfg.table.schema.value, cfg.table.name.value,
+        ),
+        &[],
+    )?;
+
+    Ok(rows
+        .iter()
+        .map(|row| AppliedRevision { id: row.get("id") })
+        .collect())
+}